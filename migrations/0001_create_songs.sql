@@ -0,0 +1,8 @@
+-- the songs table as it was first created, IF NOT EXISTS so databases from before migrations keep their rows
+CREATE TABLE IF NOT EXISTS songs(
+    id INTEGER PRIMARY KEY ASC,
+    title TEXT NOT NULL,
+    artist TEXT NOT NULL,
+    genre TEXT NOT NULL,
+    play_count INTEGER DEFAULT 0
+);
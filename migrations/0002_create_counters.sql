@@ -0,0 +1,6 @@
+-- counters that persist across restarts, starting the site visit count at 0
+CREATE TABLE IF NOT EXISTS counters(
+    name TEXT PRIMARY KEY,
+    value INTEGER NOT NULL DEFAULT 0
+);
+INSERT OR IGNORE INTO counters(name, value) VALUES ('site_visit_count', 0);
@@ -0,0 +1,10 @@
+-- named playlists and the songs in each of them
+CREATE TABLE IF NOT EXISTS playlists(
+    id INTEGER PRIMARY KEY ASC,
+    name TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS playlist_songs(
+    playlist_id INTEGER NOT NULL REFERENCES playlists(id) ON DELETE CASCADE,
+    song_id INTEGER NOT NULL REFERENCES songs(id) ON DELETE CASCADE,
+    PRIMARY KEY (playlist_id, song_id)
+);
@@ -0,0 +1 @@
+ALTER TABLE songs ADD COLUMN likes INTEGER DEFAULT 0;
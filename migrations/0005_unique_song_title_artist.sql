@@ -0,0 +1,2 @@
+-- the same title and artist can only be added once, ignoring case
+CREATE UNIQUE INDEX IF NOT EXISTS songs_title_artist_unique ON songs(LOWER(title), LOWER(artist));
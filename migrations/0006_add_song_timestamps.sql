@@ -0,0 +1,4 @@
+-- sqlite can not add a column with a CURRENT_TIMESTAMP default, so existing rows are backfilled and writes set these explicitly
+ALTER TABLE songs ADD COLUMN created_at TIMESTAMP;
+ALTER TABLE songs ADD COLUMN updated_at TIMESTAMP;
+UPDATE songs SET created_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP;
@@ -0,0 +1,2 @@
+-- nullable so songs added before albums existed have no album
+ALTER TABLE songs ADD COLUMN album TEXT;
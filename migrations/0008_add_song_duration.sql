@@ -0,0 +1,2 @@
+-- nullable so songs added before durations were tracked are left out of the totals
+ALTER TABLE songs ADD COLUMN duration_secs INTEGER;
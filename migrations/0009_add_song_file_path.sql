@@ -0,0 +1,2 @@
+-- where the uploaded audio for a song is stored on disk, null until one is uploaded
+ALTER TABLE songs ADD COLUMN file_path TEXT;
@@ -0,0 +1,7 @@
+-- one row per play so the time of each play is kept, songs.play_count stays the running total
+CREATE TABLE IF NOT EXISTS play_events(
+    id INTEGER PRIMARY KEY ASC,
+    song_id INTEGER NOT NULL REFERENCES songs(id) ON DELETE CASCADE,
+    played_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS play_events_song_played_at ON play_events(song_id, played_at);
@@ -0,0 +1,10 @@
+-- every filter compares LOWER(column) so the exact matches need expression indexes, a plain index on the column is never used for them
+-- exact title matches are already covered by songs_title_artist_unique since LOWER(title) is its first column
+CREATE INDEX IF NOT EXISTS songs_artist_lower ON songs(LOWER(artist));
+CREATE INDEX IF NOT EXISTS songs_genre_lower ON songs(LOWER(genre));
+CREATE INDEX IF NOT EXISTS songs_album_lower ON songs(LOWER(album));
+-- the plain columns are what sort=title, GROUP BY artist, and DISTINCT genre read in order
+CREATE INDEX IF NOT EXISTS songs_title ON songs(title);
+CREATE INDEX IF NOT EXISTS songs_artist ON songs(artist);
+CREATE INDEX IF NOT EXISTS songs_genre ON songs(genre);
+-- a LIKE '%term%' substring search can not use any of these and still scans the table, /songs/search/fts is the fast path for it
@@ -0,0 +1,4 @@
+-- deleting a song only sets deleted_at so it can be restored, the row and its play history stay
+ALTER TABLE songs ADD COLUMN deleted_at TIMESTAMP;
+-- the songs that are not deleted, reads go through this so a deleted song does not show up anywhere it used to
+CREATE VIEW IF NOT EXISTS live_songs AS SELECT * FROM songs WHERE deleted_at IS NULL;
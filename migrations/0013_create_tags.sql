@@ -0,0 +1,13 @@
+-- free form labels a song can have any number of, unlike its single genre
+CREATE TABLE IF NOT EXISTS tags(
+    id INTEGER PRIMARY KEY ASC,
+    -- "Workout" and "workout" are the same tag
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE
+);
+CREATE TABLE IF NOT EXISTS song_tags(
+    song_id INTEGER NOT NULL REFERENCES songs(id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (song_id, tag_id)
+);
+-- the primary key only helps from the song side, /songs/by-tag/{tag} starts from the tag
+CREATE INDEX IF NOT EXISTS song_tags_tag_id ON song_tags(tag_id);
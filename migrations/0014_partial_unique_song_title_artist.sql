@@ -0,0 +1,3 @@
+-- a deleted song no longer holds on to its title and artist, so the same song can be added again while the old row waits to be restored
+DROP INDEX IF EXISTS songs_title_artist_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS songs_title_artist_unique ON songs(LOWER(title), LOWER(artist)) WHERE deleted_at IS NULL;
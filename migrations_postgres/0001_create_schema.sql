@@ -0,0 +1,63 @@
+-- the schema migrations/ builds up to by 0014 in one step, postgres databases start here instead of replaying the sqlite history
+-- BIGSERIAL stands in for INTEGER PRIMARY KEY since postgres has no rowid for the id to alias
+-- timestamps are text in the 'YYYY-MM-DD HH:MM:SS' form sqlite's CURRENT_TIMESTAMP writes so both backends return the same strings
+CREATE TABLE IF NOT EXISTS songs(
+    id BIGSERIAL PRIMARY KEY,
+    title TEXT NOT NULL,
+    artist TEXT NOT NULL,
+    genre TEXT NOT NULL,
+    album TEXT,
+    duration_secs BIGINT,
+    play_count BIGINT DEFAULT 0,
+    likes BIGINT DEFAULT 0,
+    file_path TEXT,
+    created_at TEXT DEFAULT to_char(now() AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI:SS'),
+    updated_at TEXT DEFAULT to_char(now() AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI:SS'),
+    deleted_at TEXT
+);
+-- the same title and artist can only be added once ignoring case, a deleted song does not count
+CREATE UNIQUE INDEX IF NOT EXISTS songs_title_artist_unique ON songs(LOWER(title), LOWER(artist)) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS songs_artist_lower ON songs(LOWER(artist));
+CREATE INDEX IF NOT EXISTS songs_genre_lower ON songs(LOWER(genre));
+CREATE INDEX IF NOT EXISTS songs_album_lower ON songs(LOWER(album));
+CREATE INDEX IF NOT EXISTS songs_title ON songs(title);
+CREATE INDEX IF NOT EXISTS songs_artist ON songs(artist);
+CREATE INDEX IF NOT EXISTS songs_genre ON songs(genre);
+-- the songs that are not deleted, reads go through this so a deleted song does not show up anywhere it used to
+CREATE OR REPLACE VIEW live_songs AS SELECT * FROM songs WHERE deleted_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS counters(
+    name TEXT PRIMARY KEY,
+    value BIGINT NOT NULL DEFAULT 0
+);
+INSERT INTO counters(name, value) VALUES ('site_visit_count', 0) ON CONFLICT DO NOTHING;
+
+CREATE TABLE IF NOT EXISTS playlists(
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS playlist_songs(
+    playlist_id BIGINT NOT NULL REFERENCES playlists(id) ON DELETE CASCADE,
+    song_id BIGINT NOT NULL REFERENCES songs(id) ON DELETE CASCADE,
+    PRIMARY KEY (playlist_id, song_id)
+);
+
+CREATE TABLE IF NOT EXISTS play_events(
+    id BIGSERIAL PRIMARY KEY,
+    song_id BIGINT NOT NULL REFERENCES songs(id) ON DELETE CASCADE,
+    played_at TEXT NOT NULL DEFAULT to_char(now() AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI:SS')
+);
+CREATE INDEX IF NOT EXISTS play_events_song_played_at ON play_events(song_id, played_at);
+
+-- postgres has no COLLATE NOCASE so "Workout" and "workout" are kept the same tag by an index on the lowered name
+CREATE TABLE IF NOT EXISTS tags(
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL
+);
+CREATE UNIQUE INDEX IF NOT EXISTS tags_name_lower ON tags(LOWER(name));
+CREATE TABLE IF NOT EXISTS song_tags(
+    song_id BIGINT NOT NULL REFERENCES songs(id) ON DELETE CASCADE,
+    tag_id BIGINT NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (song_id, tag_id)
+);
+CREATE INDEX IF NOT EXISTS song_tags_tag_id ON song_tags(tag_id);
@@ -0,0 +1,7 @@
+-- the 'YYYY-MM-DD HH:MM:SS' UTC text sqlite's CURRENT_TIMESTAMP gives, so the queries set updated_at and deleted_at the same way the column defaults do
+CREATE OR REPLACE FUNCTION utc_timestamp() RETURNS TEXT AS $$
+    SELECT to_char(now() AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI:SS')
+$$ LANGUAGE SQL STABLE;
+
+-- stands in for the sqlite songs_fts table, /songs/search/fts matches against the same expression so the index is used
+CREATE INDEX IF NOT EXISTS songs_search ON songs USING GIN (to_tsvector('simple', title || ' ' || artist || ' ' || genre));